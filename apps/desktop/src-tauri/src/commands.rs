@@ -119,6 +119,8 @@ pub async fn list_runs(
     db::list_runs(&conn, status.as_deref()).map_err(map_err)
 }
 
+// the log is appended while a run is in progress, so this also serves a
+// running command's output so far
 #[tauri::command]
 pub async fn read_run_output(
     app: AppHandle,
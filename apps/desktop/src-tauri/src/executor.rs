@@ -1,9 +1,13 @@
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 use chrono::Utc;
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_dialog::{DialogExt, MessageDialogKind};
+use tokio::io::{AsyncRead, AsyncReadExt};
 use tokio::process::Command as TokioCommand;
 
 use crate::db;
@@ -53,6 +57,10 @@ pub fn run_command(app: &AppHandle, command_id: &str) -> Result<Run, String> {
         RunMode::Foreground
     };
 
+    // both modes write the log as the command runs, so the path is known up front
+    // and read_run_output can serve partial output before the run finishes
+    let output_path = state.logs_dir().join(format!("{run_id}.log"));
+
     let run = Run {
         id: run_id,
         command_id: command.id.clone(),
@@ -63,7 +71,7 @@ pub fn run_command(app: &AppHandle, command_id: &str) -> Result<Run, String> {
         duration_ms: None,
         exit_code: None,
         output_preview: String::new(),
-        output_path: None,
+        output_path: Some(output_path.to_string_lossy().to_string()),
     };
 
     {
@@ -127,7 +135,7 @@ fn spawn_background(app: AppHandle, command: Command, run: Run) {
     tauri::async_runtime::spawn(async move {
         let started = Utc::now();
 
-        let child = match build_process(&command) {
+        let mut child = match build_process(&command) {
             Ok(c) => c,
             Err(e) => {
                 let err_msg = format!("Failed to spawn process: {e}");
@@ -157,7 +165,39 @@ fn spawn_background(app: AppHandle, command: Command, run: Run) {
             }
         }
 
-        let output = child.wait_with_output().await;
+        fs::create_dir_all(&logs_dir).ok();
+        let output_file = logs_dir.join(format!("{run_id}.log"));
+        let sink = Arc::new(OutputSink {
+            app: app.clone(),
+            run_id: run_id.clone(),
+            log: Mutex::new(fs::File::create(&output_file).ok()),
+            seq: AtomicU64::new(0),
+        });
+
+        let mut readers = Vec::new();
+        if let Some(stdout) = child.stdout.take() {
+            readers.push(tauri::async_runtime::spawn(pipe_output(
+                stdout,
+                OutputStream::Stdout,
+                sink.clone(),
+            )));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            readers.push(tauri::async_runtime::spawn(pipe_output(
+                stderr,
+                OutputStream::Stderr,
+                sink.clone(),
+            )));
+        }
+
+        let exit = child.wait().await;
+
+        // the pipes hit EOF once the process exits, so this only drains what is
+        // already buffered before the log is read back for the preview
+        for reader in readers {
+            let _ = reader.await;
+        }
+        drop(sink);
 
         {
             let state = app.state::<AppState>();
@@ -165,14 +205,16 @@ fn spawn_background(app: AppHandle, command: Command, run: Run) {
             pids.remove(&run_id);
         }
 
-        match output {
-            Ok(output) => {
-                let combined = format_combined_output(&output.stdout, &output.stderr);
-                let exit_code = output.status.code();
+        match exit {
+            Ok(exit_status) => {
+                let exit_code = exit_status.code();
                 let status = match exit_code {
                     Some(0) => RunStatus::Success,
                     _ => RunStatus::Failed,
                 };
+                let output = fs::read(&output_file)
+                    .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+                    .unwrap_or_default();
 
                 finalize(
                     &app,
@@ -182,8 +224,8 @@ fn spawn_background(app: AppHandle, command: Command, run: Run) {
                     &RawRunResult {
                         status,
                         exit_code,
-                        output: &combined,
-                        output_path: None,
+                        output: &output,
+                        output_path: Some(output_file.to_string_lossy().to_string()),
                     },
                     &logs_dir,
                 )
@@ -210,6 +252,74 @@ fn spawn_background(app: AppHandle, command: Command, run: Run) {
     });
 }
 
+// shared by the stdout and stderr readers of one background run, so both streams
+// land in a single log file in arrival order with one sequence counter
+struct OutputSink {
+    app: AppHandle,
+    run_id: String,
+    log: Mutex<Option<fs::File>>,
+    seq: AtomicU64,
+}
+
+impl OutputSink {
+    fn append(&self, stream: OutputStream, bytes: &[u8], chunk: String) {
+        let mut log = self.log.lock().unwrap();
+        if let Some(file) = log.as_mut() {
+            let _ = file.write_all(bytes);
+        }
+
+        if chunk.is_empty() {
+            return;
+        }
+
+        let seq = self.seq.fetch_add(1, Ordering::SeqCst);
+        let _ = self.app.emit(
+            "run://output",
+            RunOutputPayload {
+                run_id: self.run_id.clone(),
+                stream,
+                seq,
+                chunk,
+            },
+        );
+    }
+}
+
+async fn pipe_output<R>(mut reader: R, stream: OutputStream, sink: Arc<OutputSink>)
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buf = [0u8; 8192];
+    // bytes of a multi-byte character split across reads, held back so events
+    // never carry a half-decoded character
+    let mut pending: Vec<u8> = Vec::new();
+
+    loop {
+        let n = match reader.read(&mut buf).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => n,
+        };
+
+        pending.extend_from_slice(&buf[..n]);
+        let complete = complete_utf8_len(&pending);
+        let chunk = String::from_utf8_lossy(&pending[..complete]).into_owned();
+        sink.append(stream, &buf[..n], chunk);
+        pending.drain(..complete);
+    }
+
+    if !pending.is_empty() {
+        sink.append(stream, &[], String::from_utf8_lossy(&pending).into_owned());
+    }
+}
+
+fn complete_utf8_len(bytes: &[u8]) -> usize {
+    match std::str::from_utf8(bytes) {
+        Ok(_) => bytes.len(),
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        Err(_) => bytes.len(),
+    }
+}
+
 fn spawn_foreground(app: AppHandle, command: Command, run: Run) {
     let run_id = run.id.clone();
     let command_id = command.id.clone();
@@ -503,19 +613,6 @@ fn expand_tilde(path: &str) -> PathBuf {
     PathBuf::from(path)
 }
 
-fn format_combined_output(stdout: &[u8], stderr: &[u8]) -> String {
-    let stdout_str = String::from_utf8_lossy(stdout);
-    let stderr_str = String::from_utf8_lossy(stderr);
-
-    if stderr_str.is_empty() {
-        stdout_str.into_owned()
-    } else if stdout_str.is_empty() {
-        stderr_str.into_owned()
-    } else {
-        format!("{stdout_str}\n{stderr_str}")
-    }
-}
-
 fn generate_unix_wrapper_script(
     command: &Command,
     output_path: &Path,
@@ -641,7 +738,7 @@ fn open_terminal(script_path: &Path) -> Result<(), String> {
                 return Ok(());
             }
         }
-        return Err("No supported terminal emulator found".to_string());
+        Err("No supported terminal emulator found".to_string())
     }
 
     #[cfg(windows)]
@@ -80,6 +80,13 @@ impl RunMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum ScheduleMode {
@@ -199,6 +206,15 @@ pub struct RunCompletedPayload {
     pub last_run_status: CommandStatus,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct RunOutputPayload {
+    pub run_id: String,
+    pub stream: OutputStream,
+    /// Increments across both streams of a run, so chunks can be ordered as written.
+    pub seq: u64,
+    pub chunk: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct RunCompletion {
     pub status: RunStatus,
@@ -9,6 +9,7 @@ import type {
   BackendSchedule,
   BackendScheduleInput,
   CommandStatus,
+  OutputStream,
   UpdateInfo,
 } from "../types";
 
@@ -23,6 +24,13 @@ export type RunCompletedPayload = {
   last_run_status: CommandStatus;
 };
 
+export type RunOutputPayload = {
+  run_id: string;
+  stream: OutputStream;
+  seq: number;
+  chunk: string;
+};
+
 export const api = {
   listGroups: () => invoke<BackendGroup[]>("list_groups"),
   createGroup: (input: BackendGroupInput) =>
@@ -85,6 +93,14 @@ export function onRunCompleted(
   );
 }
 
+export function onRunOutput(
+  callback: (payload: RunOutputPayload) => void,
+): Promise<UnlistenFn> {
+  return listen<RunOutputPayload>("run://output", (event) =>
+    callback(event.payload),
+  );
+}
+
 export function onUpdateAvailable(
   callback: (payload: UpdateInfo) => void,
 ): Promise<UnlistenFn> {
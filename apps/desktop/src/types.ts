@@ -6,6 +6,8 @@ export type RunStatus = "running" | "success" | "failed";
 
 export type RunMode = "background" | "foreground";
 
+export type OutputStream = "stdout" | "stderr";
+
 export type ScheduleMode = "once" | "recurring";
 
 export type BackendGroup = {